@@ -0,0 +1,183 @@
+use crate::{IoBackend, PortAddress};
+
+/// The x86 port-mapped I/O backend, emitting `in`/`out` instructions.
+///
+/// This is the default backend for [`ReadOnlyPort`](crate::ReadOnlyPort) and
+/// its siblings. Its [`IoBackend`] implementation is only available on
+/// `x86_64`; on other targets the port wrappers must be parameterised over a
+/// different backend such as [`MmioIo`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct PortIo;
+
+#[cfg(target_arch = "x86_64")]
+impl IoBackend for PortIo {
+    type Addr = PortAddress;
+
+    unsafe fn read_u8(address: PortAddress) -> u8 {
+        let value: u8;
+        unsafe {
+            core::arch::asm!("in al, dx", out("al") value, in("dx") address, options(nomem, nostack, preserves_flags));
+        }
+        value
+    }
+
+    unsafe fn read_u16(address: PortAddress) -> u16 {
+        let value: u16;
+        unsafe {
+            core::arch::asm!("in ax, dx", out("ax") value, in("dx") address, options(nomem, nostack, preserves_flags));
+        }
+        value
+    }
+
+    unsafe fn read_u32(address: PortAddress) -> u32 {
+        let value: u32;
+        unsafe {
+            core::arch::asm!("in eax, dx", out("eax") value, in("dx") address, options(nomem, nostack, preserves_flags));
+        }
+        value
+    }
+
+    unsafe fn write_u8(address: PortAddress, value: u8) {
+        unsafe {
+            core::arch::asm!("out dx, al", in("dx") address, in("al") value, options(nomem, nostack, preserves_flags));
+        }
+    }
+
+    unsafe fn write_u16(address: PortAddress, value: u16) {
+        unsafe {
+            core::arch::asm!("out dx, ax", in("dx") address, in("ax") value, options(nomem, nostack, preserves_flags));
+        }
+    }
+
+    unsafe fn write_u32(address: PortAddress, value: u32) {
+        unsafe {
+            core::arch::asm!("out dx, eax", in("dx") address, in("eax") value, options(nomem, nostack, preserves_flags));
+        }
+    }
+
+    unsafe fn read_into_u8(address: PortAddress, buf: &mut [u8]) {
+        unsafe {
+            core::arch::asm!(
+                "rep insb",
+                in("dx") address,
+                inout("rdi") buf.as_mut_ptr() => _,
+                inout("rcx") buf.len() => _,
+                options(nostack, preserves_flags)
+            );
+        }
+    }
+
+    unsafe fn read_into_u16(address: PortAddress, buf: &mut [u16]) {
+        unsafe {
+            core::arch::asm!(
+                "rep insw",
+                in("dx") address,
+                inout("rdi") buf.as_mut_ptr() => _,
+                inout("rcx") buf.len() => _,
+                options(nostack, preserves_flags)
+            );
+        }
+    }
+
+    unsafe fn read_into_u32(address: PortAddress, buf: &mut [u32]) {
+        unsafe {
+            core::arch::asm!(
+                "rep insd",
+                in("dx") address,
+                inout("rdi") buf.as_mut_ptr() => _,
+                inout("rcx") buf.len() => _,
+                options(nostack, preserves_flags)
+            );
+        }
+    }
+
+    unsafe fn write_slice_u8(address: PortAddress, buf: &[u8]) {
+        unsafe {
+            core::arch::asm!(
+                "rep outsb",
+                in("dx") address,
+                inout("rsi") buf.as_ptr() => _,
+                inout("rcx") buf.len() => _,
+                options(nostack, preserves_flags)
+            );
+        }
+    }
+
+    unsafe fn write_slice_u16(address: PortAddress, buf: &[u16]) {
+        unsafe {
+            core::arch::asm!(
+                "rep outsw",
+                in("dx") address,
+                inout("rsi") buf.as_ptr() => _,
+                inout("rcx") buf.len() => _,
+                options(nostack, preserves_flags)
+            );
+        }
+    }
+
+    unsafe fn write_slice_u32(address: PortAddress, buf: &[u32]) {
+        unsafe {
+            core::arch::asm!(
+                "rep outsd",
+                in("dx") address,
+                inout("rsi") buf.as_ptr() => _,
+                inout("rcx") buf.len() => _,
+                options(nostack, preserves_flags)
+            );
+        }
+    }
+}
+
+/// A memory-mapped I/O backend that performs volatile accesses through a
+/// full-width address reinterpreted as a raw pointer.
+///
+/// This allows the port wrappers to drive memory-mapped device registers mapped
+/// anywhere in the address space (PCIe BARs, AArch64/RISC-V device windows) on
+/// architectures without a separate I/O address space, so it addresses with a
+/// `usize` rather than the 16-bit [`PortAddress`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct MmioIo;
+
+impl IoBackend for MmioIo {
+    type Addr = usize;
+
+    unsafe fn read_u8(address: usize) -> u8 {
+        unsafe { (address as *const u8).read_volatile() }
+    }
+
+    unsafe fn read_u16(address: usize) -> u16 {
+        unsafe { (address as *const u16).read_volatile() }
+    }
+
+    unsafe fn read_u32(address: usize) -> u32 {
+        unsafe { (address as *const u32).read_volatile() }
+    }
+
+    unsafe fn write_u8(address: usize, value: u8) {
+        unsafe { (address as *mut u8).write_volatile(value) }
+    }
+
+    unsafe fn write_u16(address: usize, value: u16) {
+        unsafe { (address as *mut u16).write_volatile(value) }
+    }
+
+    unsafe fn write_u32(address: usize, value: u32) {
+        unsafe { (address as *mut u32).write_volatile(value) }
+    }
+}
+
+/// The backend used by the port wrappers when none is named explicitly.
+///
+/// This resolves to [`PortIo`] on `x86_64`, where port-mapped I/O is the native
+/// mechanism, and to [`MmioIo`] elsewhere so that the port types remain
+/// well-formed on targets without an `in`/`out` instruction set.
+#[cfg(target_arch = "x86_64")]
+pub type DefaultBackend = PortIo;
+
+/// The backend used by the port wrappers when none is named explicitly.
+///
+/// This resolves to [`PortIo`] on `x86_64`, where port-mapped I/O is the native
+/// mechanism, and to [`MmioIo`] elsewhere so that the port types remain
+/// well-formed on targets without an `in`/`out` instruction set.
+#[cfg(not(target_arch = "x86_64"))]
+pub type DefaultBackend = MmioIo;
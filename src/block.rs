@@ -0,0 +1,174 @@
+use crate::{DefaultBackend, IoAddress, IoBackend, PortReadWrite, ReadWritePort};
+use core::marker::PhantomData;
+
+/// A contiguous bank of device registers laid out at `base + index * stride`
+/// addressing units, handed out as individual [`ReadWritePort`] handles.
+///
+/// This turns a single `unsafe` construction of the bank into safe, indexed
+/// access over the whole range, so callers need not hand-compute every port
+/// address. The bank is generic over the access backend `B`, so a register
+/// file reached over MMIO is built the same way as a legacy port range.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PortBlock<T: PortReadWrite, B: IoBackend = DefaultBackend> {
+    base: B::Addr,
+    stride: usize,
+    len: usize,
+    marker: PhantomData<(T, B)>,
+}
+
+impl<T: PortReadWrite, B: IoBackend> PortBlock<T, B> {
+    /// Creates a block of `len` register ports starting at `base`, each
+    /// `stride` addressing units apart.
+    ///
+    /// ### Safety
+    ///
+    /// - Every address in `[base, base + (len - 1) * stride]` must point to a
+    ///   valid device register.
+    /// - The range should not be otherwise aliased.
+    /// - Each port must be valid for reading and writing types of size `T`.
+    pub const unsafe fn new(base: B::Addr, stride: usize, len: usize) -> Self {
+        PortBlock {
+            base,
+            stride,
+            len,
+            marker: PhantomData,
+        }
+    }
+
+    /// The number of register ports in the block.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the block contains no ports.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the port handle at index `n`, or `None` if `n` is out of bounds.
+    pub fn index(&self, n: usize) -> Option<ReadWritePort<T, B>> {
+        if n < self.len {
+            Some(unsafe { self.index_unchecked(n) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the port handle at index `n` without bounds checking.
+    ///
+    /// ### Safety
+    ///
+    /// - `n` must be less than [`len`](Self::len).
+    pub unsafe fn index_unchecked(&self, n: usize) -> ReadWritePort<T, B> {
+        let address = self.base.add_units(self.stride * n);
+        unsafe { ReadWritePort::new(address) }
+    }
+
+    /// Returns an iterator yielding each port handle in the block, in order.
+    pub fn iter(&self) -> PortBlockIter<'_, T, B> {
+        PortBlockIter { block: self, next: 0 }
+    }
+}
+
+/// An iterator over the port handles of a [`PortBlock`].
+#[derive(Debug, Clone)]
+pub struct PortBlockIter<'a, T: PortReadWrite, B: IoBackend = DefaultBackend> {
+    block: &'a PortBlock<T, B>,
+    next: usize,
+}
+
+impl<T: PortReadWrite, B: IoBackend> Iterator for PortBlockIter<'_, T, B> {
+    type Item = ReadWritePort<T, B>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let port = self.block.index(self.next)?;
+        self.next += 1;
+        Some(port)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.block.len - self.next;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T: PortReadWrite, B: IoBackend> ExactSizeIterator for PortBlockIter<'_, T, B> {}
+
+impl<'a, T: PortReadWrite, B: IoBackend> IntoIterator for &'a PortBlock<T, B> {
+    type Item = ReadWritePort<T, B>;
+    type IntoIter = PortBlockIter<'a, T, B>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PortAddress;
+
+    /// A no-op port backend used to exercise block addressing without touching
+    /// real hardware.
+    struct MockIo;
+
+    impl IoBackend for MockIo {
+        type Addr = PortAddress;
+
+        unsafe fn read_u8(_address: PortAddress) -> u8 {
+            0
+        }
+        unsafe fn read_u16(_address: PortAddress) -> u16 {
+            0
+        }
+        unsafe fn read_u32(_address: PortAddress) -> u32 {
+            0
+        }
+        unsafe fn write_u8(_address: PortAddress, _value: u8) {}
+        unsafe fn write_u16(_address: PortAddress, _value: u16) {}
+        unsafe fn write_u32(_address: PortAddress, _value: u32) {}
+    }
+
+    fn block(base: PortAddress, stride: usize, len: usize) -> PortBlock<u8, MockIo> {
+        unsafe { PortBlock::new(base, stride, len) }
+    }
+
+    #[test]
+    fn index_computes_strided_addresses() {
+        let block = block(0x300, 4, 3);
+        assert_eq!(block.index(0).unwrap().address(), 0x300);
+        assert_eq!(block.index(1).unwrap().address(), 0x304);
+        assert_eq!(block.index(2).unwrap().address(), 0x308);
+    }
+
+    #[test]
+    fn index_is_bounds_checked() {
+        let block = block(0x300, 4, 3);
+        assert!(block.index(3).is_none());
+        assert!(!block.is_empty());
+        assert_eq!(block.len(), 3);
+    }
+
+    #[test]
+    fn iter_yields_every_handle_in_order() {
+        let addresses: [PortAddress; 3] = core::array::from_fn(|i| 0x300 + i as PortAddress * 4);
+        let block = block(0x300, 4, 3);
+        let collected: [PortAddress; 3] = {
+            let mut out = [0; 3];
+            for (slot, port) in out.iter_mut().zip(block.iter()) {
+                *slot = port.address();
+            }
+            out
+        };
+        assert_eq!(collected, addresses);
+        assert_eq!(block.iter().count(), 3);
+    }
+
+    #[test]
+    fn high_addressed_index_saturates_instead_of_overflowing() {
+        // `base + stride * n` would overflow a `u16` without the saturating add.
+        let block = block(0xFFF0, 0x100, 4);
+        assert_eq!(block.index(0).unwrap().address(), 0xFFF0);
+        assert_eq!(block.index(3).unwrap().address(), u16::MAX);
+    }
+}
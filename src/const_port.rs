@@ -0,0 +1,138 @@
+use crate::{
+    PortRead, PortReadWrite, PortWrite, ReadOnlyPort, ReadWritePort, WriteOnlyPort,
+};
+use core::marker::PhantomData;
+
+/// A zero-sized read-only port handle whose address is encoded in the type.
+///
+/// Because the port number is a compile-time constant, the handle carries no
+/// runtime address field and occupies no space, which suits fixed legacy ports
+/// such as the keyboard controller.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct ConstReadOnlyPort<const ADDR: u16, T: PortRead> {
+    marker: PhantomData<T>,
+}
+
+impl<const ADDR: u16, T: PortRead> ConstReadOnlyPort<ADDR, T> {
+    /// Creates a read-only handle for the compile-time port `ADDR`.
+    ///
+    /// ### Safety
+    ///
+    /// - `ADDR` must point to a valid device.
+    /// - `ADDR` should not be otherwise aliased.
+    /// - Port must be valid for reading types of size `T`.
+    pub const unsafe fn new() -> Self {
+        ConstReadOnlyPort { marker: PhantomData }
+    }
+
+    /// The address of the port.
+    pub const fn address(&self) -> u16 {
+        ADDR
+    }
+
+    /// Reads a `T` from the port.
+    pub fn read(&self) -> T {
+        unsafe { T::read::<crate::PortIo>(ADDR) }
+    }
+
+    /// Reads `buf.len()` consecutive `T`s from the port into `buf`.
+    pub fn read_into(&self, buf: &mut [T]) {
+        unsafe { T::read_into::<crate::PortIo>(ADDR, buf) }
+    }
+
+    /// Converts this handle into the runtime [`ReadOnlyPort`] for code paths
+    /// that need a dynamic address.
+    pub const fn into_dynamic(self) -> ReadOnlyPort<T> {
+        unsafe { ReadOnlyPort::new(ADDR) }
+    }
+}
+
+/// A zero-sized write-only port handle whose address is encoded in the type.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct ConstWriteOnlyPort<const ADDR: u16, T: PortWrite> {
+    marker: PhantomData<T>,
+}
+
+impl<const ADDR: u16, T: PortWrite> ConstWriteOnlyPort<ADDR, T> {
+    /// Creates a write-only handle for the compile-time port `ADDR`.
+    ///
+    /// ### Safety
+    ///
+    /// - `ADDR` must point to a valid device.
+    /// - `ADDR` should not be otherwise aliased.
+    /// - Port must be valid for writing types of size `T`.
+    pub const unsafe fn new() -> Self {
+        ConstWriteOnlyPort { marker: PhantomData }
+    }
+
+    /// The address of the port.
+    pub const fn address(&self) -> u16 {
+        ADDR
+    }
+
+    /// Writes a `T` to the port.
+    pub fn write(&mut self, value: T) {
+        unsafe { T::write::<crate::PortIo>(ADDR, value) }
+    }
+
+    /// Writes all of `buf` to the port in order.
+    pub fn write_slice(&mut self, buf: &[T]) {
+        unsafe { T::write_slice::<crate::PortIo>(ADDR, buf) }
+    }
+
+    /// Converts this handle into the runtime [`WriteOnlyPort`] for code paths
+    /// that need a dynamic address.
+    pub const fn into_dynamic(self) -> WriteOnlyPort<T> {
+        unsafe { WriteOnlyPort::new(ADDR) }
+    }
+}
+
+/// A zero-sized read-write port handle whose address is encoded in the type.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct ConstReadWritePort<const ADDR: u16, T: PortReadWrite> {
+    marker: PhantomData<T>,
+}
+
+impl<const ADDR: u16, T: PortReadWrite> ConstReadWritePort<ADDR, T> {
+    /// Creates a read-write handle for the compile-time port `ADDR`.
+    ///
+    /// ### Safety
+    ///
+    /// - `ADDR` must point to a valid device.
+    /// - `ADDR` should not be otherwise aliased.
+    /// - Port must be valid for reading and writing types of size `T`.
+    pub const unsafe fn new() -> Self {
+        ConstReadWritePort { marker: PhantomData }
+    }
+
+    /// The address of the port.
+    pub const fn address(&self) -> u16 {
+        ADDR
+    }
+
+    /// Reads a `T` from the port.
+    pub fn read(&self) -> T {
+        unsafe { T::read::<crate::PortIo>(ADDR) }
+    }
+
+    /// Reads `buf.len()` consecutive `T`s from the port into `buf`.
+    pub fn read_into(&self, buf: &mut [T]) {
+        unsafe { T::read_into::<crate::PortIo>(ADDR, buf) }
+    }
+
+    /// Writes a `T` to the port.
+    pub fn write(&mut self, value: T) {
+        unsafe { T::write::<crate::PortIo>(ADDR, value) }
+    }
+
+    /// Writes all of `buf` to the port in order.
+    pub fn write_slice(&mut self, buf: &[T]) {
+        unsafe { T::write_slice::<crate::PortIo>(ADDR, buf) }
+    }
+
+    /// Converts this handle into the runtime [`ReadWritePort`] for code paths
+    /// that need a dynamic address.
+    pub const fn into_dynamic(self) -> ReadWritePort<T> {
+        unsafe { ReadWritePort::new(ADDR) }
+    }
+}
@@ -2,6 +2,20 @@
 
 use core::marker::PhantomData;
 
+mod backend;
+pub use backend::*;
+
+mod block;
+pub use block::*;
+
+#[cfg(target_arch = "x86_64")]
+mod const_port;
+#[cfg(target_arch = "x86_64")]
+pub use const_port::*;
+
+mod registry;
+pub use registry::*;
+
 mod rw;
 pub use rw::*;
 
@@ -12,12 +26,12 @@ impl PortReadWrite for u32 {}
 /// A port wrapper that only allows read operations.
 #[repr(transparent)]
 #[derive(Debug, Clone, Eq, PartialEq)]
-pub struct ReadOnlyPort<T: PortRead> {
-    address: PortAddress,
-    marker: PhantomData<T>,
+pub struct ReadOnlyPort<T: PortRead, B: IoBackend = DefaultBackend> {
+    address: B::Addr,
+    marker: PhantomData<(T, B)>,
 }
 
-impl<T: PortRead> ReadOnlyPort<T> {
+impl<T: PortRead, B: IoBackend> ReadOnlyPort<T, B> {
     /// Creates a read-only port wrapper pointing to the provided address.
     ///
     /// ### Safety
@@ -25,7 +39,7 @@ impl<T: PortRead> ReadOnlyPort<T> {
     /// - Provided port address must point to a valid device.
     /// - Provided port address should not be otherwise aliased.
     /// - Port must be valid for reading types of size `T`.
-    pub const unsafe fn new(address: PortAddress) -> Self {
+    pub const unsafe fn new(address: B::Addr) -> Self {
         ReadOnlyPort {
             address,
             marker: PhantomData,
@@ -33,25 +47,30 @@ impl<T: PortRead> ReadOnlyPort<T> {
     }
 
     /// The address of the port.
-    pub const fn address(&self) -> PortAddress {
+    pub const fn address(&self) -> B::Addr {
         self.address
     }
 
     /// Reads a `T` from the port.
     pub fn read(&self) -> T {
-        unsafe { T::read(self.address()) }
+        unsafe { T::read::<B>(self.address()) }
+    }
+
+    /// Reads `buf.len()` consecutive `T`s from the port into `buf`.
+    pub fn read_into(&self, buf: &mut [T]) {
+        unsafe { T::read_into::<B>(self.address(), buf) }
     }
 }
 
 /// A port wrapper that only allows write operations.
 #[repr(transparent)]
 #[derive(Debug, Clone, Eq, PartialEq)]
-pub struct WriteOnlyPort<T: PortWrite> {
-    address: PortAddress,
-    marker: PhantomData<T>,
+pub struct WriteOnlyPort<T: PortWrite, B: IoBackend = DefaultBackend> {
+    address: B::Addr,
+    marker: PhantomData<(T, B)>,
 }
 
-impl<T: PortWrite> WriteOnlyPort<T> {
+impl<T: PortWrite, B: IoBackend> WriteOnlyPort<T, B> {
     /// Creates a write-only port wrapper pointing to the provided address.
     ///
     /// ### Safety
@@ -59,7 +78,7 @@ impl<T: PortWrite> WriteOnlyPort<T> {
     /// - Provided port address must point to a valid device.
     /// - Provided port address should not be otherwise aliased.
     /// - Port must be valid for reading types of size `T`.
-    pub const unsafe fn new(address: PortAddress) -> Self {
+    pub const unsafe fn new(address: B::Addr) -> Self {
         WriteOnlyPort {
             address,
             marker: PhantomData,
@@ -67,25 +86,30 @@ impl<T: PortWrite> WriteOnlyPort<T> {
     }
 
     /// The address of the port.
-    pub const fn address(&self) -> PortAddress {
+    pub const fn address(&self) -> B::Addr {
         self.address
     }
 
     /// Writes a `T` to the port.
     pub fn write(&mut self, value: T) {
-        unsafe { T::write(self.address(), value) }
+        unsafe { T::write::<B>(self.address(), value) }
+    }
+
+    /// Writes all of `buf` to the port in order.
+    pub fn write_slice(&mut self, buf: &[T]) {
+        unsafe { T::write_slice::<B>(self.address(), buf) }
     }
 }
 
 /// A port wrapper that allows read and write operations.
 #[repr(transparent)]
 #[derive(Debug, Clone, Eq, PartialEq)]
-pub struct ReadWritePort<T: PortReadWrite> {
-    address: PortAddress,
-    marker: PhantomData<T>,
+pub struct ReadWritePort<T: PortReadWrite, B: IoBackend = DefaultBackend> {
+    address: B::Addr,
+    marker: PhantomData<(T, B)>,
 }
 
-impl<T: PortReadWrite> ReadWritePort<T> {
+impl<T: PortReadWrite, B: IoBackend> ReadWritePort<T, B> {
     /// Creates a read-write port wrapper pointing to the provided address.
     ///
     /// ### Safety
@@ -93,7 +117,7 @@ impl<T: PortReadWrite> ReadWritePort<T> {
     /// - Provided port address must point to a valid device.
     /// - Provided port address should not be otherwise aliased.
     /// - Port must be valid for reading types of size `T`.
-    pub const unsafe fn new(address: PortAddress) -> Self {
+    pub const unsafe fn new(address: B::Addr) -> Self {
         ReadWritePort {
             address,
             marker: PhantomData,
@@ -101,17 +125,27 @@ impl<T: PortReadWrite> ReadWritePort<T> {
     }
 
     /// The address of the port.
-    pub const fn address(&self) -> PortAddress {
+    pub const fn address(&self) -> B::Addr {
         self.address
     }
 
     /// Reads a `T` from the port.
     pub fn read(&self) -> T {
-        unsafe { T::read(self.address()) }
+        unsafe { T::read::<B>(self.address()) }
+    }
+
+    /// Reads `buf.len()` consecutive `T`s from the port into `buf`.
+    pub fn read_into(&self, buf: &mut [T]) {
+        unsafe { T::read_into::<B>(self.address(), buf) }
     }
 
     /// Writes a `T` to the port.
     pub fn write(&mut self, value: T) {
-        unsafe { T::write(self.address(), value) }
+        unsafe { T::write::<B>(self.address(), value) }
+    }
+
+    /// Writes all of `buf` to the port in order.
+    pub fn write_slice(&mut self, buf: &[T]) {
+        unsafe { T::write_slice::<B>(self.address(), buf) }
     }
 }
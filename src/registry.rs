@@ -0,0 +1,277 @@
+use crate::{DefaultBackend, IoAddress, IoBackend, PortRead, PortReadWrite, PortWrite, ReadOnlyPort, ReadWritePort, WriteOnlyPort};
+use core::cell::UnsafeCell;
+use core::fmt;
+use core::ops::{Deref, DerefMut};
+use core::sync::atomic::{AtomicBool, Ordering};
+
+/// Returned by [`PortRegistry`] claims when the requested range overlaps a
+/// range that is already claimed, or when the registry is full.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct AlreadyClaimed;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+struct Interval<A> {
+    base: A,
+    size: usize,
+}
+
+impl<A: IoAddress> Interval<A> {
+    fn overlaps(&self, other: &Interval<A>) -> bool {
+        if self.size == 0 || other.size == 0 {
+            return false;
+        }
+        // Compare inclusive last units, so a range that saturates at the top of
+        // the address space still covers its final unit.
+        let self_last = self.base.add_units(self.size - 1);
+        let other_last = other.base.add_units(other.size - 1);
+        self.base <= other_last && other.base <= self_last
+    }
+}
+
+/// Tracks claimed `[base, base + size)` register ranges and hands out owned port
+/// guards, refusing to claim a range that overlaps an outstanding one.
+///
+/// This is the recommended path for driver code that allocates ports
+/// dynamically at boot: it upholds the "should not be otherwise aliased"
+/// invariant of the raw constructors by rejecting overlapping claims until the
+/// owning guard is dropped. The registry is generic over the access backend
+/// `B` and holds up to `N` concurrent claims.
+///
+/// The claim table is guarded by an internal spinlock, so the registry is
+/// [`Sync`] and [`new`](Self::new) is `const`: a single shared instance can
+/// live in a `static` and be claimed against from any context, which is the
+/// usual boot-time arrangement.
+pub struct PortRegistry<const N: usize, B: IoBackend = DefaultBackend> {
+    locked: AtomicBool,
+    claimed: UnsafeCell<[Option<Interval<B::Addr>>; N]>,
+}
+
+// SAFETY: all access to `claimed` goes through `with_claimed`, which holds the
+// `locked` spinlock for the duration of the access, so the `UnsafeCell` is
+// never touched concurrently. `B::Addr` is a plain integer address and carries
+// no thread affinity.
+unsafe impl<const N: usize, B: IoBackend> Sync for PortRegistry<N, B> {}
+
+impl<const N: usize, B: IoBackend> Default for PortRegistry<N, B> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize, B: IoBackend> PortRegistry<N, B> {
+    /// Creates an empty registry with room for `N` concurrent claims.
+    pub const fn new() -> Self {
+        PortRegistry {
+            locked: AtomicBool::new(false),
+            claimed: UnsafeCell::new([None; N]),
+        }
+    }
+
+    /// Runs `f` with exclusive access to the claim table, spinning until the
+    /// internal lock is free.
+    fn with_claimed<R>(&self, f: impl FnOnce(&mut [Option<Interval<B::Addr>>; N]) -> R) -> R {
+        while self
+            .locked
+            .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            core::hint::spin_loop();
+        }
+        // SAFETY: the spinlock is held, so this is the only live reference to
+        // the claim table.
+        let result = f(unsafe { &mut *self.claimed.get() });
+        self.locked.store(false, Ordering::Release);
+        result
+    }
+
+    /// Reserves `[address, address + size)` and returns the free slot index, or
+    /// [`AlreadyClaimed`] if the range overlaps an outstanding claim or the
+    /// registry is full.
+    fn claim_interval(&self, address: B::Addr, size: usize) -> Result<usize, AlreadyClaimed> {
+        let interval = Interval { base: address, size };
+        self.with_claimed(|claimed| {
+            if claimed.iter().flatten().any(|other| interval.overlaps(other)) {
+                return Err(AlreadyClaimed);
+            }
+
+            let slot = claimed.iter().position(Option::is_none).ok_or(AlreadyClaimed)?;
+            claimed[slot] = Some(interval);
+            Ok(slot)
+        })
+    }
+
+    /// Claims `[address, address + size)` and returns an owned read-write port
+    /// guard for `address`.
+    ///
+    /// The range is released when the returned guard is dropped.
+    ///
+    /// ### Safety
+    ///
+    /// - Provided port address must point to a valid device.
+    /// - Port must be valid for reading and writing types of size `T`.
+    pub unsafe fn claim<T: PortReadWrite>(
+        &self,
+        address: B::Addr,
+        size: usize,
+    ) -> Result<PortGuard<'_, ReadWritePort<T, B>, N, B>, AlreadyClaimed> {
+        let slot = self.claim_interval(address, size)?;
+        Ok(PortGuard {
+            registry: self,
+            slot,
+            port: unsafe { ReadWritePort::new(address) },
+        })
+    }
+
+    /// Claims `[address, address + size)` and returns an owned read-only port
+    /// guard for `address`.
+    ///
+    /// The range is released when the returned guard is dropped.
+    ///
+    /// ### Safety
+    ///
+    /// - Provided port address must point to a valid device.
+    /// - Port must be valid for reading types of size `T`.
+    pub unsafe fn claim_read_only<T: PortRead>(
+        &self,
+        address: B::Addr,
+        size: usize,
+    ) -> Result<PortGuard<'_, ReadOnlyPort<T, B>, N, B>, AlreadyClaimed> {
+        let slot = self.claim_interval(address, size)?;
+        Ok(PortGuard {
+            registry: self,
+            slot,
+            port: unsafe { ReadOnlyPort::new(address) },
+        })
+    }
+
+    /// Claims `[address, address + size)` and returns an owned write-only port
+    /// guard for `address`.
+    ///
+    /// The range is released when the returned guard is dropped.
+    ///
+    /// ### Safety
+    ///
+    /// - Provided port address must point to a valid device.
+    /// - Port must be valid for writing types of size `T`.
+    pub unsafe fn claim_write_only<T: PortWrite>(
+        &self,
+        address: B::Addr,
+        size: usize,
+    ) -> Result<PortGuard<'_, WriteOnlyPort<T, B>, N, B>, AlreadyClaimed> {
+        let slot = self.claim_interval(address, size)?;
+        Ok(PortGuard {
+            registry: self,
+            slot,
+            port: unsafe { WriteOnlyPort::new(address) },
+        })
+    }
+
+    /// Releases the claim occupying `slot`.
+    fn release(&self, slot: usize) {
+        self.with_claimed(|claimed| claimed[slot] = None);
+    }
+}
+
+impl<const N: usize, B: IoBackend> fmt::Debug for PortRegistry<N, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PortRegistry").finish_non_exhaustive()
+    }
+}
+
+/// An owned handle to a port range claimed from a [`PortRegistry`].
+///
+/// Dereferences to the underlying port wrapper and releases the claimed range
+/// back to the registry when dropped.
+#[derive(Debug)]
+pub struct PortGuard<'r, P, const N: usize, B: IoBackend = DefaultBackend> {
+    registry: &'r PortRegistry<N, B>,
+    slot: usize,
+    port: P,
+}
+
+impl<P, const N: usize, B: IoBackend> Deref for PortGuard<'_, P, N, B> {
+    type Target = P;
+
+    fn deref(&self) -> &Self::Target {
+        &self.port
+    }
+}
+
+impl<P, const N: usize, B: IoBackend> DerefMut for PortGuard<'_, P, N, B> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.port
+    }
+}
+
+impl<P, const N: usize, B: IoBackend> Drop for PortGuard<'_, P, N, B> {
+    fn drop(&mut self) {
+        self.registry.release(self.slot);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PortAddress;
+
+    /// A no-op port backend used to exercise the registry's bookkeeping without
+    /// touching real hardware.
+    struct MockIo;
+
+    impl IoBackend for MockIo {
+        type Addr = PortAddress;
+
+        unsafe fn read_u8(_address: PortAddress) -> u8 {
+            0
+        }
+        unsafe fn read_u16(_address: PortAddress) -> u16 {
+            0
+        }
+        unsafe fn read_u32(_address: PortAddress) -> u32 {
+            0
+        }
+        unsafe fn write_u8(_address: PortAddress, _value: u8) {}
+        unsafe fn write_u16(_address: PortAddress, _value: u16) {}
+        unsafe fn write_u32(_address: PortAddress, _value: u32) {}
+    }
+
+    fn interval(base: PortAddress, size: usize) -> Interval<PortAddress> {
+        Interval { base, size }
+    }
+
+    #[test]
+    fn adjacent_ranges_do_not_overlap() {
+        assert!(!interval(0x10, 0x10).overlaps(&interval(0x20, 0x10)));
+        assert!(!interval(0x20, 0x10).overlaps(&interval(0x10, 0x10)));
+    }
+
+    #[test]
+    fn ranges_sharing_a_port_overlap() {
+        assert!(interval(0x10, 0x11).overlaps(&interval(0x20, 0x10)));
+        assert!(interval(0x20, 0x10).overlaps(&interval(0x18, 0x10)));
+    }
+
+    #[test]
+    fn claim_at_top_of_port_space_does_not_overflow() {
+        let top = interval(0xFFF0, 0x20);
+        // Would panic on a debug `0xFFF0u16 + 0x20` without the saturating add.
+        assert!(top.overlaps(&top));
+        assert!(!top.overlaps(&interval(0x0, 0x10)));
+    }
+
+    #[test]
+    fn overlapping_claim_is_rejected_until_release() {
+        let registry = PortRegistry::<2, MockIo>::new();
+        let first = unsafe { registry.claim::<u8>(0x3F8, 8) }.expect("first claim");
+        assert!(unsafe { registry.claim::<u8>(0x3FC, 1) }.is_err());
+        drop(first);
+        let _second = unsafe { registry.claim::<u8>(0x3FC, 1) }.expect("claim after release");
+    }
+
+    #[test]
+    fn registry_is_full_when_all_slots_taken() {
+        let registry = PortRegistry::<1, MockIo>::new();
+        let _first = unsafe { registry.claim::<u8>(0x10, 1) }.expect("first claim");
+        assert!(unsafe { registry.claim::<u8>(0x20, 1) }.is_err());
+    }
+}
@@ -0,0 +1,246 @@
+/// The type used to address a port-mapped I/O port.
+pub type PortAddress = u16;
+
+/// An address usable by an [`IoBackend`].
+///
+/// Port-mapped backends address registers with a 16-bit [`PortAddress`], while
+/// memory-mapped backends need a full-width pointer value to reach registers
+/// mapped anywhere in the address space; this trait abstracts over both.
+pub trait IoAddress: Copy + Ord + core::fmt::Debug {
+    /// Returns this address advanced by `units` addressing units, saturating at
+    /// the top of the address space rather than wrapping or overflowing.
+    fn add_units(self, units: usize) -> Self;
+}
+
+impl IoAddress for u16 {
+    fn add_units(self, units: usize) -> Self {
+        self.saturating_add(u16::try_from(units).unwrap_or(u16::MAX))
+    }
+}
+
+impl IoAddress for usize {
+    fn add_units(self, units: usize) -> Self {
+        self.saturating_add(units)
+    }
+}
+
+/// A backend that performs the primitive reads and writes underlying a port.
+///
+/// The default [`PortIo`](crate::PortIo) backend emits the x86 `in`/`out`
+/// instructions and addresses with a [`PortAddress`], while
+/// [`MmioIo`](crate::MmioIo) performs volatile accesses through a full-width
+/// `usize` address interpreted as a raw pointer. Implementing this trait lets
+/// the same high-level port wrappers drive other access mechanisms.
+pub trait IoBackend {
+    /// The address type this backend uses to locate a register.
+    type Addr: IoAddress;
+
+    /// Reads a `u8` from `address`.
+    ///
+    /// ### Safety
+    ///
+    /// - `address` must be valid for an 8-bit read on this backend.
+    unsafe fn read_u8(address: Self::Addr) -> u8;
+    /// Reads a `u16` from `address`.
+    ///
+    /// ### Safety
+    ///
+    /// - `address` must be valid for a 16-bit read on this backend.
+    unsafe fn read_u16(address: Self::Addr) -> u16;
+    /// Reads a `u32` from `address`.
+    ///
+    /// ### Safety
+    ///
+    /// - `address` must be valid for a 32-bit read on this backend.
+    unsafe fn read_u32(address: Self::Addr) -> u32;
+
+    /// Writes a `u8` to `address`.
+    ///
+    /// ### Safety
+    ///
+    /// - `address` must be valid for an 8-bit write on this backend.
+    unsafe fn write_u8(address: Self::Addr, value: u8);
+    /// Writes a `u16` to `address`.
+    ///
+    /// ### Safety
+    ///
+    /// - `address` must be valid for a 16-bit write on this backend.
+    unsafe fn write_u16(address: Self::Addr, value: u16);
+    /// Writes a `u32` to `address`.
+    ///
+    /// ### Safety
+    ///
+    /// - `address` must be valid for a 32-bit write on this backend.
+    unsafe fn write_u32(address: Self::Addr, value: u32);
+
+    /// Reads `buf.len()` consecutive `u8`s from `address` into `buf`.
+    ///
+    /// The default implementation issues one scalar read per element; backends
+    /// that can move the whole block at once should override it.
+    ///
+    /// ### Safety
+    ///
+    /// - `address` must be valid for the whole transfer on this backend.
+    unsafe fn read_into_u8(address: Self::Addr, buf: &mut [u8]) {
+        for slot in buf {
+            *slot = unsafe { Self::read_u8(address) };
+        }
+    }
+    /// Reads `buf.len()` consecutive `u16`s from `address` into `buf`.
+    ///
+    /// ### Safety
+    ///
+    /// - `address` must be valid for the whole transfer on this backend.
+    unsafe fn read_into_u16(address: Self::Addr, buf: &mut [u16]) {
+        for slot in buf {
+            *slot = unsafe { Self::read_u16(address) };
+        }
+    }
+    /// Reads `buf.len()` consecutive `u32`s from `address` into `buf`.
+    ///
+    /// ### Safety
+    ///
+    /// - `address` must be valid for the whole transfer on this backend.
+    unsafe fn read_into_u32(address: Self::Addr, buf: &mut [u32]) {
+        for slot in buf {
+            *slot = unsafe { Self::read_u32(address) };
+        }
+    }
+
+    /// Writes all of `buf` to `address` in order.
+    ///
+    /// ### Safety
+    ///
+    /// - `address` must be valid for the whole transfer on this backend.
+    unsafe fn write_slice_u8(address: Self::Addr, buf: &[u8]) {
+        for &value in buf {
+            unsafe { Self::write_u8(address, value) };
+        }
+    }
+    /// Writes all of `buf` to `address` in order.
+    ///
+    /// ### Safety
+    ///
+    /// - `address` must be valid for the whole transfer on this backend.
+    unsafe fn write_slice_u16(address: Self::Addr, buf: &[u16]) {
+        for &value in buf {
+            unsafe { Self::write_u16(address, value) };
+        }
+    }
+    /// Writes all of `buf` to `address` in order.
+    ///
+    /// ### Safety
+    ///
+    /// - `address` must be valid for the whole transfer on this backend.
+    unsafe fn write_slice_u32(address: Self::Addr, buf: &[u32]) {
+        for &value in buf {
+            unsafe { Self::write_u32(address, value) };
+        }
+    }
+}
+
+/// A type that can be read from a port through an [`IoBackend`].
+pub trait PortRead {
+    /// Reads a single value of this type from the given port.
+    ///
+    /// ### Safety
+    ///
+    /// - Provided port address must point to a valid device.
+    /// - Port must be valid for reading a value of this type.
+    unsafe fn read<B: IoBackend>(address: B::Addr) -> Self;
+
+    /// Reads `buf.len()` consecutive values of this type from the given port
+    /// into `buf`.
+    ///
+    /// ### Safety
+    ///
+    /// - Provided port address must point to a valid device.
+    /// - Port must be valid for reading values of this type.
+    unsafe fn read_into<B: IoBackend>(address: B::Addr, buf: &mut [Self])
+    where
+        Self: Sized;
+}
+
+/// A type that can be written to a port through an [`IoBackend`].
+pub trait PortWrite {
+    /// Writes a single value of this type to the given port.
+    ///
+    /// ### Safety
+    ///
+    /// - Provided port address must point to a valid device.
+    /// - Port must be valid for writing a value of this type.
+    unsafe fn write<B: IoBackend>(address: B::Addr, value: Self);
+
+    /// Writes all of `buf` to the given port in order.
+    ///
+    /// ### Safety
+    ///
+    /// - Provided port address must point to a valid device.
+    /// - Port must be valid for writing values of this type.
+    unsafe fn write_slice<B: IoBackend>(address: B::Addr, buf: &[Self])
+    where
+        Self: Sized;
+}
+
+/// A type that can be both read from and written to a port.
+pub trait PortReadWrite: PortRead + PortWrite {}
+
+impl PortRead for u8 {
+    unsafe fn read<B: IoBackend>(address: B::Addr) -> Self {
+        unsafe { B::read_u8(address) }
+    }
+
+    unsafe fn read_into<B: IoBackend>(address: B::Addr, buf: &mut [Self]) {
+        unsafe { B::read_into_u8(address, buf) }
+    }
+}
+
+impl PortRead for u16 {
+    unsafe fn read<B: IoBackend>(address: B::Addr) -> Self {
+        unsafe { B::read_u16(address) }
+    }
+
+    unsafe fn read_into<B: IoBackend>(address: B::Addr, buf: &mut [Self]) {
+        unsafe { B::read_into_u16(address, buf) }
+    }
+}
+
+impl PortRead for u32 {
+    unsafe fn read<B: IoBackend>(address: B::Addr) -> Self {
+        unsafe { B::read_u32(address) }
+    }
+
+    unsafe fn read_into<B: IoBackend>(address: B::Addr, buf: &mut [Self]) {
+        unsafe { B::read_into_u32(address, buf) }
+    }
+}
+
+impl PortWrite for u8 {
+    unsafe fn write<B: IoBackend>(address: B::Addr, value: Self) {
+        unsafe { B::write_u8(address, value) }
+    }
+
+    unsafe fn write_slice<B: IoBackend>(address: B::Addr, buf: &[Self]) {
+        unsafe { B::write_slice_u8(address, buf) }
+    }
+}
+
+impl PortWrite for u16 {
+    unsafe fn write<B: IoBackend>(address: B::Addr, value: Self) {
+        unsafe { B::write_u16(address, value) }
+    }
+
+    unsafe fn write_slice<B: IoBackend>(address: B::Addr, buf: &[Self]) {
+        unsafe { B::write_slice_u16(address, buf) }
+    }
+}
+
+impl PortWrite for u32 {
+    unsafe fn write<B: IoBackend>(address: B::Addr, value: Self) {
+        unsafe { B::write_u32(address, value) }
+    }
+
+    unsafe fn write_slice<B: IoBackend>(address: B::Addr, buf: &[Self]) {
+        unsafe { B::write_slice_u32(address, buf) }
+    }
+}